@@ -29,6 +29,7 @@
 use chrono::prelude::*;
 use itertools::{self, Itertools};
 use regex::Regex;
+use std::cmp::Ordering;
 use std::fmt;
 use std::num::ParseIntError;
 use std::ops::Add;
@@ -58,7 +59,7 @@ impl ComposerChecker {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, EnumString, strum_macros::Display)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, EnumString, strum_macros::Display)]
 #[strum(serialize_all = "lowercase", ascii_case_insensitive)]
 enum SemVerPrefix {
     V,
@@ -81,10 +82,83 @@ enum SemVerSuffix {
 
 // FIXME: technically "dev44" should not be supported
 
+/// A single dot-separated pre-release identifier.
+///
+/// The semver spec treats each identifier as either a numeric value or an
+/// alphanumeric token, and the distinction drives both rendering and
+/// precedence.
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Identifier {
+    Numeric(u64),
+    AlphaNumeric(String),
+}
+
+impl FromStr for Identifier {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // A chunk made entirely of digits is numeric, everything else is
+        // kept verbatim as an alphanumeric identifier.
+        Ok(match s.parse::<u64>() {
+            Ok(value) => Identifier::Numeric(value),
+            Err(_) => Identifier::AlphaNumeric(s.to_string()),
+        })
+    }
+}
+
+impl Ord for Identifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Identifier::Numeric(a), Identifier::Numeric(b)) => a.cmp(b),
+            (Identifier::AlphaNumeric(a), Identifier::AlphaNumeric(b)) => a.cmp(b),
+            // A purely numeric identifier always ranks below an alphanumeric one.
+            (Identifier::Numeric(_), Identifier::AlphaNumeric(_)) => Ordering::Less,
+            (Identifier::AlphaNumeric(_), Identifier::Numeric(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for Identifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Identifier::Numeric(value) => write!(f, "{value}"),
+            Identifier::AlphaNumeric(value) => write!(f, "{value}"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 struct SemVerPair {
     suffix: SemVerSuffix,
-    version: Option<i32>,
+    identifiers: Vec<Identifier>,
+}
+
+impl Ord for SemVerPair {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The suffix name is itself an alphanumeric identifier, compared by
+        // ASCII lexical order. Lower-case the `Display` form first so the
+        // upper-case `RC` does not sort ahead of the lower-case suffixes (the
+        // parser already lower-cases its input). Then walk the remaining
+        // identifiers field-by-field; `Vec`'s lexicographic `Ord` gives the
+        // "more identifiers wins" prefix tie-break for free.
+        self.suffix
+            .to_string()
+            .to_lowercase()
+            .cmp(&other.suffix.to_string().to_lowercase())
+            .then_with(|| self.identifiers.cmp(&other.identifiers))
+    }
+}
+
+impl PartialOrd for SemVerPair {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -138,15 +212,31 @@ impl fmt::Display for ZeroPaddedInt {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, Clone)]
 pub struct SemVer {
     prefix: Option<SemVerPrefix>,
     major: ZeroPaddedInt,
     minor: ZeroPaddedInt,
     patch: ZeroPaddedInt,
     suffix: Option<SemVerPair>,
+    /// Dot-separated build metadata identifiers (the `+...` segment). Kept
+    /// verbatim and, per spec, excluded from equality and precedence.
+    build: Vec<String>,
 }
 
+impl PartialEq for SemVer {
+    fn eq(&self, other: &Self) -> bool {
+        // Build metadata must be ignored when comparing versions.
+        self.prefix == other.prefix
+            && self.major == other.major
+            && self.minor == other.minor
+            && self.patch == other.patch
+            && self.suffix == other.suffix
+    }
+}
+
+impl Eq for SemVer {}
+
 impl SemVer {
     pub fn increment_major(self) -> Self {
         Self {
@@ -170,11 +260,46 @@ impl SemVer {
     }
 }
 
+impl Ord for SemVer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Precedence compares the numeric triple first, ignoring any
+        // zero-padding width recorded in `ZeroPaddedInt`.
+        self.major
+            .value
+            .cmp(&other.major.value)
+            .then_with(|| self.minor.value.cmp(&other.minor.value))
+            .then_with(|| self.patch.value.cmp(&other.patch.value))
+            .then_with(|| match (&self.suffix, &other.suffix) {
+                // A version with a pre-release suffix has lower precedence
+                // than one without any suffix.
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
+            // Precedence ignores the prefix and the zero-padding width, but
+            // `PartialEq` does not, so break any remaining tie on exactly those
+            // fields to keep `Ord` consistent with `Eq` (`build` is excluded
+            // from both). Without this, equal-precedence-but-unequal values
+            // would collapse in a `BTreeSet`.
+            .then_with(|| self.prefix.cmp(&other.prefix))
+            .then_with(|| self.major.width.cmp(&other.major.width))
+            .then_with(|| self.minor.width.cmp(&other.minor.width))
+            .then_with(|| self.patch.width.cmp(&other.patch.width))
+    }
+}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Display for SemVer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{}{}.{}.{}{}",
+            "{}{}.{}.{}{}{}",
             self.prefix
                 .as_ref()
                 .map_or_else(|| "".to_string(), |p| p.to_string()),
@@ -183,32 +308,75 @@ impl fmt::Display for SemVer {
             self.patch,
             self.suffix.as_ref().map_or_else(
                 || "".to_string(),
-                |suffix| format!(
-                    "-{}{}",
-                    suffix.suffix,
-                    suffix
-                        .version
-                        .map_or_else(|| "".to_string(), |v| v.to_string())
-                )
-            )
+                |suffix| format!("-{}{}", suffix.suffix, render_identifiers(&suffix.identifiers))
+            ),
+            if self.build.is_empty() {
+                "".to_string()
+            } else {
+                format!("+{}", self.build.join("."))
+            }
         )
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct ParseSemVerError;
+/// The ways parsing a version (or a [`VersionReq`]) can fail.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseSemVerError {
+    /// The input did not contain a single usable component.
+    Empty,
+    /// Fewer than the required `major.minor` components were present.
+    TooFewComponents,
+    /// A component that must be numeric could not be parsed as an integer.
+    InvalidNumber {
+        component: &'static str,
+        value: String,
+    },
+    /// A pre-release suffix name was not one of the recognized keywords.
+    UnknownSuffix(String),
+    /// Input remained after the whole version had been consumed.
+    TrailingGarbage(String),
+}
+
+impl fmt::Display for ParseSemVerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseSemVerError::Empty => write!(f, "empty version string"),
+            ParseSemVerError::TooFewComponents => {
+                write!(f, "too few version components")
+            }
+            ParseSemVerError::InvalidNumber { component, value } => {
+                write!(f, "invalid {component} number: {value:?}")
+            }
+            ParseSemVerError::UnknownSuffix(suffix) => {
+                write!(f, "unknown version suffix: {suffix:?}")
+            }
+            ParseSemVerError::TrailingGarbage(rest) => {
+                write!(f, "trailing characters after version: {rest:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseSemVerError {}
 
 impl FromStr for SemVer {
     type Err = ParseSemVerError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Peel off the optional `+...` build metadata first and keep its
+        // dot-separated identifiers verbatim.
+        let (s, build) = match s.split_once('+') {
+            Some((version, meta)) => (version, meta.split('.').map(str::to_string).collect_vec()),
+            None => (s, Vec::new()),
+        };
+
         let mut parts = s
             .split(&['-', '_', '.'])
             .flat_map(split_alpha_and_number)
             .map(|t| t.to_lowercase().to_string())
             .collect_vec();
-        if parts.is_empty() {
-            return Err(ParseSemVerError);
+        if parts.iter().all(|p| p.is_empty()) {
+            return Err(ParseSemVerError::Empty);
         }
 
         // 1) Parse the prefix component, if any.
@@ -218,6 +386,9 @@ impl FromStr for SemVer {
         if prefix.is_some() || parts[0] == "release" {
             parts.remove(0);
         }
+        if parts.len() < 2 {
+            return Err(ParseSemVerError::TooFewComponents);
+        }
         // support month name as the second component
         if let Ok(month) = parts[1].parse::<Month>() {
             parts[1] = month.number_from_month().to_string();
@@ -225,7 +396,7 @@ impl FromStr for SemVer {
 
         match parts.len() {
             ..=1 => {
-                return Err(ParseSemVerError);
+                return Err(ParseSemVerError::TooFewComponents);
             }
             2 => {
                 parts.push("0".to_string());
@@ -239,36 +410,44 @@ impl FromStr for SemVer {
             suffix_part = SemVerSuffix::from_str(&parts[3]).ok();
             if suffix_part.is_some() {
                 parts.remove(3);
-            } else {
+            } else if parts[3] == "v" {
                 // support: 2023-11-29-v1
-                if parts[3] == "v" {
-                    parts.remove(3);
-                }
+                parts.remove(3);
+            } else if parts[3].parse::<u64>().is_err() {
+                // The component right after the triple is the suffix keyword;
+                // a non-numeric token we don't recognize is a malformed suffix
+                // rather than a silently-accepted pre-release identifier.
+                return Err(ParseSemVerError::UnknownSuffix(parts[3].clone()));
             }
         }
 
-        // 3) Parse the suffix version (4 version number in the format).
-        let mut suffix_version = None;
-        if parts.len() >= 4 {
-            suffix_version = Some(parts[3].parse::<i32>().unwrap());
-            parts.remove(3);
+        // 3) Collect every remaining chunk after the suffix keyword as a
+        //    pre-release identifier (e.g. `alpha.1.2` -> alpha + [1, 2]).
+        let identifiers = parts
+            .drain(3..)
+            .map(|p| Identifier::from_str(&p).unwrap())
+            .collect_vec();
 
-            // Make a default suffix name "P" if the is not any.
-            if suffix_part.is_none() {
-                suffix_part = Some(SemVerSuffix::default());
-            }
+        // Make a default suffix name "P" if there is not any.
+        if !identifiers.is_empty() && suffix_part.is_none() {
+            suffix_part = Some(SemVerSuffix::default());
         }
         let suffix = suffix_part.map(|sp| SemVerPair {
             suffix: sp,
-            version: suffix_version,
+            identifiers,
         });
 
-        // FIXME: if any part is not a number, the following code will panic
         let integer_parts = parts
             .iter()
             .take(3)
-            .map(|p| ZeroPaddedInt::from_str(p).unwrap())
-            .collect_vec();
+            .zip(["major", "minor", "patch"])
+            .map(|(p, component)| {
+                ZeroPaddedInt::from_str(p).map_err(|_| ParseSemVerError::InvalidNumber {
+                    component,
+                    value: p.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(SemVer {
             major: integer_parts[0],
@@ -276,10 +455,321 @@ impl FromStr for SemVer {
             patch: integer_parts[2],
             prefix,
             suffix,
+            build,
         })
     }
 }
 
+/// A single comparison operator used inside a [`VersionReq`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+}
+
+/// A fully expanded comparator: an operator applied to a concrete version.
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Comparator {
+    op: Op,
+    version: SemVer,
+}
+
+impl Comparator {
+    fn matches(&self, version: &SemVer) -> bool {
+        let ordering = version.cmp(&self.version);
+        match self.op {
+            Op::Exact => version == &self.version,
+            Op::Greater => ordering == Ordering::Greater,
+            Op::GreaterEq => ordering != Ordering::Less,
+            Op::Less => ordering == Ordering::Less,
+            Op::LessEq => ordering != Ordering::Greater,
+        }
+    }
+}
+
+/// A set of version requirements such as `^1.2`, `~1.2.3` or `>=1.0.0, <2.0.0`.
+///
+/// Parse one with [`FromStr`] and test a parsed [`SemVer`] against it with
+/// [`VersionReq::matches`]. A requirement is satisfied only when every
+/// comparator holds.
+///
+/// ```
+/// use std::str::FromStr;
+/// use semantic_version_parser::{SemVer, VersionReq};
+///
+/// let req = VersionReq::from_str("^1.2.3").unwrap();
+/// assert!(req.matches(&SemVer::from_str("1.4.0").unwrap()));
+/// assert!(!req.matches(&SemVer::from_str("2.0.0").unwrap()));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct VersionReq {
+    comparators: Vec<Comparator>,
+}
+
+impl VersionReq {
+    /// Returns `true` when `version` satisfies every comparator in the set.
+    ///
+    /// Following the usual convention, a version carrying a pre-release suffix
+    /// is only considered a match when some comparator pins the same
+    /// `major.minor.patch` triple.
+    pub fn matches(&self, version: &SemVer) -> bool {
+        if !self.comparators.iter().all(|c| c.matches(version)) {
+            return false;
+        }
+
+        if version.suffix.is_some() {
+            return self.comparators.iter().any(|c| {
+                c.version.major.value == version.major.value
+                    && c.version.minor.value == version.minor.value
+                    && c.version.patch.value == version.patch.value
+            });
+        }
+
+        true
+    }
+}
+
+/// A partially specified version (`1`, `1.2`, `1.2.*`), used while expanding
+/// comparators into concrete ranges.
+struct PartialVersion {
+    major: u32,
+    minor: Option<u32>,
+    patch: Option<u32>,
+}
+
+impl PartialVersion {
+    fn lower_bound(&self) -> SemVer {
+        bounded_semver(self.major, self.minor.unwrap_or(0), self.patch.unwrap_or(0))
+    }
+}
+
+fn bounded_semver(major: u32, minor: u32, patch: u32) -> SemVer {
+    SemVer {
+        prefix: None,
+        major: ZeroPaddedInt::from(major),
+        minor: ZeroPaddedInt::from(minor),
+        patch: ZeroPaddedInt::from(patch),
+        suffix: None,
+        build: Vec::new(),
+    }
+}
+
+fn parse_partial(s: &str) -> Result<PartialVersion, ParseSemVerError> {
+    let mut components = [None, None, None];
+    for (index, token) in s.split('.').enumerate() {
+        if index >= 3 {
+            return Err(ParseSemVerError::TrailingGarbage(token.to_string()));
+        }
+        if matches!(token, "*" | "x" | "X" | "") {
+            // A wildcard ends the meaningful components.
+            break;
+        }
+        components[index] = Some(token.parse::<u32>().map_err(|_| {
+            ParseSemVerError::InvalidNumber {
+                component: "version requirement",
+                value: token.to_string(),
+            }
+        })?);
+    }
+
+    Ok(PartialVersion {
+        major: components[0].unwrap_or(0),
+        minor: components[1],
+        patch: components[2],
+    })
+}
+
+/// Either a fully parsed version (possibly with a pre-release suffix) or a
+/// partially specified one that still needs expanding into a range.
+enum ReqVersion {
+    Full(SemVer),
+    Partial(PartialVersion),
+}
+
+fn parse_req_version(s: &str) -> Result<ReqVersion, ParseSemVerError> {
+    // Treat it as a full version only when all three components are present
+    // and none of them is a wildcard; everything else stays partial.
+    let tokens = s.split('.').collect_vec();
+    let has_wildcard = tokens.iter().any(|t| matches!(*t, "*" | "x" | "X" | ""));
+    if tokens.len() >= 3 && !has_wildcard {
+        return Ok(ReqVersion::Full(SemVer::from_str(s)?));
+    }
+
+    Ok(ReqVersion::Partial(parse_partial(s)?))
+}
+
+fn parse_comparator(s: &str) -> Result<Vec<Comparator>, ParseSemVerError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseSemVerError::Empty);
+    }
+
+    // Recognize the operator prefix, longest match first.
+    let (op, rest) = if let Some(rest) = s.strip_prefix(">=") {
+        (Some(Op::GreaterEq), rest)
+    } else if let Some(rest) = s.strip_prefix("<=") {
+        (Some(Op::LessEq), rest)
+    } else if let Some(rest) = s.strip_prefix('>') {
+        (Some(Op::Greater), rest)
+    } else if let Some(rest) = s.strip_prefix('<') {
+        (Some(Op::Less), rest)
+    } else if let Some(rest) = s.strip_prefix('=') {
+        (Some(Op::Exact), rest)
+    } else if let Some(rest) = s.strip_prefix('^') {
+        return caret(parse_partial(rest.trim())?);
+    } else if let Some(rest) = s.strip_prefix('~') {
+        return tilde(parse_partial(rest.trim())?);
+    } else {
+        (None, s)
+    };
+
+    match parse_req_version(rest.trim())? {
+        // A fully specified version keeps its exact bound (and any suffix).
+        ReqVersion::Full(version) => Ok(vec![Comparator {
+            op: op.unwrap_or(Op::Exact),
+            version,
+        }]),
+        // A bare operator just fills the missing components with zero; no
+        // operator (or `=`) expands the partial version into a range.
+        ReqVersion::Partial(partial) => match op {
+            Some(Op::Exact) | None => Ok(wildcard(partial)),
+            Some(op) => Ok(vec![Comparator {
+                op,
+                version: partial.lower_bound(),
+            }]),
+        },
+    }
+}
+
+fn caret(partial: PartialVersion) -> Result<Vec<Comparator>, ParseSemVerError> {
+    // Bump the left-most non-zero component, zeroing everything to its right.
+    let upper = if partial.major != 0 {
+        bounded_semver(partial.major + 1, 0, 0)
+    } else {
+        match (partial.minor, partial.patch) {
+            (None, _) => bounded_semver(1, 0, 0),
+            (Some(0), None) => bounded_semver(0, 1, 0),
+            (Some(0), Some(patch)) => bounded_semver(0, 0, patch + 1),
+            (Some(minor), _) => bounded_semver(0, minor + 1, 0),
+        }
+    };
+
+    Ok(vec![
+        Comparator {
+            op: Op::GreaterEq,
+            version: partial.lower_bound(),
+        },
+        Comparator {
+            op: Op::Less,
+            version: upper,
+        },
+    ])
+}
+
+fn tilde(partial: PartialVersion) -> Result<Vec<Comparator>, ParseSemVerError> {
+    let upper = match partial.minor {
+        Some(minor) => bounded_semver(partial.major, minor + 1, 0),
+        None => bounded_semver(partial.major + 1, 0, 0),
+    };
+
+    Ok(vec![
+        Comparator {
+            op: Op::GreaterEq,
+            version: partial.lower_bound(),
+        },
+        Comparator {
+            op: Op::Less,
+            version: upper,
+        },
+    ])
+}
+
+fn wildcard(partial: PartialVersion) -> Vec<Comparator> {
+    // A fully specified version pins an exact match; anything with a wildcard
+    // or omitted tail becomes the obvious bounded range.
+    match (partial.minor, partial.patch) {
+        (Some(_), Some(_)) => vec![Comparator {
+            op: Op::Exact,
+            version: partial.lower_bound(),
+        }],
+        (Some(minor), None) => vec![
+            Comparator {
+                op: Op::GreaterEq,
+                version: bounded_semver(partial.major, minor, 0),
+            },
+            Comparator {
+                op: Op::Less,
+                version: bounded_semver(partial.major, minor + 1, 0),
+            },
+        ],
+        _ => vec![
+            Comparator {
+                op: Op::GreaterEq,
+                version: bounded_semver(partial.major, 0, 0),
+            },
+            Comparator {
+                op: Op::Less,
+                version: bounded_semver(partial.major + 1, 0, 0),
+            },
+        ],
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = ParseSemVerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let comparators = s
+            .split(',')
+            .map(parse_comparator)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect_vec();
+        if comparators.is_empty() {
+            return Err(ParseSemVerError::Empty);
+        }
+
+        Ok(VersionReq { comparators })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SemVer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        // Serialize as the canonical string form rather than the internal
+        // struct layout.
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SemVer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let version = <String as serde::Deserialize>::deserialize(deserializer)?;
+        SemVer::from_str(&version).map_err(serde::de::Error::custom)
+    }
+}
+
+fn render_identifiers(identifiers: &[Identifier]) -> String {
+    match identifiers {
+        // Keep the historical glued form (`-beta1`, `-p1`) for the common
+        // single numeric identifier so the composer output stays valid.
+        [] => String::new(),
+        [Identifier::Numeric(value)] => value.to_string(),
+        identifiers => identifiers.iter().map(|id| format!(".{id}")).collect(),
+    }
+}
+
 fn split_alpha_and_number(s: &str) -> Vec<&str> {
     let number_start = s.chars().position(|c| c.is_numeric());
     if let Some(number_start) = number_start {
@@ -364,7 +854,8 @@ mod tests {
                 major: ZeroPaddedInt::from(1),
                 minor: ZeroPaddedInt::from(2),
                 patch: ZeroPaddedInt::from(3),
-                suffix: None
+                suffix: None,
+                build: Vec::new()
             })
         );
         assert_eq!(
@@ -376,8 +867,9 @@ mod tests {
                 patch: ZeroPaddedInt::from(0),
                 suffix: Some(SemVerPair {
                     suffix: SemVerSuffix::Beta,
-                    version: Some(1)
-                })
+                    identifiers: vec![Identifier::Numeric(1)]
+                }),
+                build: Vec::new()
             })
         );
         assert_eq!(
@@ -387,7 +879,8 @@ mod tests {
                 major: ZeroPaddedInt::from(2022),
                 minor: ZeroPaddedInt::new(2, 2),
                 patch: ZeroPaddedInt::new(9, 2),
-                suffix: None
+                suffix: None,
+                build: Vec::new()
             })
         );
         assert_eq!(
@@ -399,8 +892,9 @@ mod tests {
                 patch: ZeroPaddedInt::from(2023),
                 suffix: Some(SemVerPair {
                     suffix: SemVerSuffix::P,
-                    version: Some(1)
-                })
+                    identifiers: vec![Identifier::Numeric(1)]
+                }),
+                build: Vec::new()
             })
         );
         assert_eq!(
@@ -412,8 +906,9 @@ mod tests {
                 patch: ZeroPaddedInt::from(29),
                 suffix: Some(SemVerPair {
                     suffix: SemVerSuffix::P,
-                    version: Some(1)
-                })
+                    identifiers: vec![Identifier::Numeric(1)]
+                }),
+                build: Vec::new()
             })
         );
         assert_eq!(
@@ -425,8 +920,9 @@ mod tests {
                 patch: ZeroPaddedInt::from(0),
                 suffix: Some(SemVerPair {
                     suffix: SemVerSuffix::Alpha,
-                    version: Some(0)
-                })
+                    identifiers: vec![Identifier::Numeric(0)]
+                }),
+                build: Vec::new()
             })
         );
 
@@ -439,16 +935,45 @@ mod tests {
                 patch: ZeroPaddedInt::from(27),
                 suffix: Some(SemVerPair {
                     suffix: SemVerSuffix::P,
-                    version: Some(1)
-                })
+                    identifiers: vec![Identifier::Numeric(1)]
+                }),
+                build: Vec::new()
             })
         );
     }
 
     #[test]
-    #[should_panic]
+    fn build_metadata_parsing() {
+        let semver = SemVer::from_str("1.2.3+20130922.linux").unwrap();
+        assert_eq!(semver.build, vec!["20130922", "linux"]);
+        assert_eq!(semver.to_string(), "1.2.3+20130922.linux");
+
+        let semver = SemVer::from_str("0.8.1-rc.3+build.5").unwrap();
+        assert_eq!(semver.build, vec!["build", "5"]);
+        assert_eq!(semver.to_string(), "0.8.1-RC3+build.5");
+
+        // Build metadata is excluded from equality comparisons.
+        assert_eq!(
+            SemVer::from_str("1.2.3+build.1"),
+            SemVer::from_str("1.2.3+build.2")
+        );
+    }
+
+    #[test]
     fn parse_invalid_semantic_versions() {
-        assert!(SemVer::from_str("foo.bar.baz").is_err());
+        assert_eq!(
+            SemVer::from_str("foo.bar.baz"),
+            Err(ParseSemVerError::InvalidNumber {
+                component: "major",
+                value: "foo".to_string()
+            })
+        );
+        assert_eq!(SemVer::from_str(""), Err(ParseSemVerError::Empty));
+        assert_eq!(SemVer::from_str("v1"), Err(ParseSemVerError::TooFewComponents));
+        assert_eq!(
+            SemVer::from_str("1.0.0-weird7"),
+            Err(ParseSemVerError::UnknownSuffix("weird".to_string()))
+        );
     }
 
     #[test]
@@ -472,6 +997,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn semantic_version_ordering() {
+        let parse = |s| SemVer::from_str(s).unwrap();
+
+        assert!(parse("1.0.0") < parse("2.0.0"));
+        assert!(parse("1.2.0") < parse("1.3.0"));
+        assert!(parse("1.2.3") < parse("1.2.4"));
+        // zero-padding width does not affect numeric precedence, but since
+        // `Eq` distinguishes the two, `Ord` must break the tie rather than
+        // report `Equal` (otherwise they would collide in a `BTreeSet`)
+        assert_ne!(parse("1.02.3"), parse("1.2.3"));
+        assert_ne!(parse("1.02.3").cmp(&parse("1.2.3")), Ordering::Equal);
+        // a pre-release has lower precedence than the released version
+        assert!(parse("1.0.0-alpha1") < parse("1.0.0"));
+        // numeric suffix identifiers compare numerically
+        assert!(parse("1.0.0-alpha1") < parse("1.0.0-alpha2"));
+        // the upper-case `RC` suffix still ranks above beta, not below it
+        assert!(parse("1.0.0-beta1") < parse("1.0.0-rc1"));
+        // the suffix carrying more identifiers wins the prefix tie-break
+        assert!(parse("1.0.0-beta") < parse("1.0.0-beta1"));
+
+        let mut versions = [parse("1.0.0"), parse("1.0.0-beta1"), parse("0.9.0")];
+        versions.sort();
+        assert_eq!(
+            versions.iter().map(|v| v.to_string()).collect_vec(),
+            vec!["0.9.0", "1.0.0-beta1", "1.0.0"]
+        );
+    }
+
+    #[test]
+    fn version_req_matching() {
+        let matches = |req: &str, version: &str| {
+            VersionReq::from_str(req)
+                .unwrap()
+                .matches(&SemVer::from_str(version).unwrap())
+        };
+
+        // caret
+        assert!(matches("^1.2.3", "1.2.3"));
+        assert!(matches("^1.2.3", "1.9.0"));
+        assert!(!matches("^1.2.3", "2.0.0"));
+        assert!(matches("^0.2.3", "0.2.5"));
+        assert!(!matches("^0.2.3", "0.3.0"));
+
+        // tilde
+        assert!(matches("~1.2.3", "1.2.9"));
+        assert!(!matches("~1.2.3", "1.3.0"));
+
+        // comparator set
+        assert!(matches(">=1.0.0, <2.0.0", "1.5.0"));
+        assert!(!matches(">=1.0.0, <2.0.0", "2.0.0"));
+
+        // wildcard / partial
+        assert!(matches("1.2.*", "1.2.7"));
+        assert!(!matches("1.2.*", "1.3.0"));
+
+        // a pre-release only matches when a comparator pins the same triple
+        assert!(!matches("^1.2.3", "1.3.0-rc1"));
+        assert!(matches(">=1.2.3-rc1, <2.0.0", "1.2.3-rc1"));
+    }
+
     #[test]
     fn increment_version() {
         let semver = SemVer::from_str("v2023-Nov-0027-v1").unwrap();